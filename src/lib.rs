@@ -20,6 +20,7 @@
 mod panic_guard;
 mod remainder;
 
+use core::iter::FusedIterator;
 use core::mem::{forget, MaybeUninit};
 
 use panic_guard::ChunkPanicGuard;
@@ -36,6 +37,13 @@ pub struct ConstChunks<const N: usize, I: Iterator> {
     /// This field is None if the underlying iterator hasn't been completely consumed
     /// or if there are no remaining items.
     remainder: Option<ConstChunksRemainder<N, I::Item>>,
+    /// Whether the trailing items that can't fill a chunk have already been discarded
+    /// from the back of `inner`.
+    ///
+    /// Chunks are front-aligned, so the first call to [`DoubleEndedIterator::next_back`]
+    /// must take `inner.len() % N` items off the back (moving them into `remainder`)
+    /// before it can assemble a full chunk. This flag makes sure that only happens once.
+    trailing_discarded: bool,
 }
 
 impl<const N: usize, I: Iterator> ConstChunks<N, I> {
@@ -64,6 +72,25 @@ impl<const N: usize, I: Iterator> ConstChunks<N, I> {
     pub fn into_remainder(self) -> Option<ConstChunksRemainder<N, I::Item>> {
         self.remainder
     }
+
+    /// Borrows the remainder that could not fill a chunk completely, without consuming it.
+    ///
+    /// Returns `None` until the leftover has been split off (by `next` once the underlying
+    /// iterator runs dry, or by the first `next_back` call), or if there is no leftover.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use const_chunks::IteratorConstChunks;
+    ///
+    /// let mut v_iter = vec![1, 2, 3, 4, 5, 6].into_iter().const_chunks::<4>();
+    /// let _ = (&mut v_iter).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v_iter.remainder(), Some(&[5, 6][..]));
+    /// ```
+    pub fn remainder(&self) -> Option<&[I::Item]> {
+        self.remainder.as_ref().map(ConstChunksRemainder::as_slice)
+    }
 }
 
 impl<const N: usize, I: Iterator> Iterator for ConstChunks<N, I> {
@@ -123,9 +150,60 @@ impl<const N: usize, I: Iterator> Iterator for ConstChunks<N, I> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        // When `inner` is an `ExactSizeIterator`, its own `size_hint` is required to
+        // already return `(inner.len(), Some(inner.len()))`, so this stays exact: both
+        // bounds come out to `inner.len() / N`.
         let (lower, upper) = self.inner.size_hint();
         (lower / N, upper.map(|upper| upper / N))
     }
+
+    // `try_fold` can't be overridden here: doing so would require naming the standard
+    // library's `Try` trait, which is still unstable (see rust-lang/rust#84277). `fold`
+    // has no such restriction, so we specialize it instead; this already speeds up
+    // callers like `sum`/`for_each`, which are implemented in terms of `fold`.
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // Reusable buffer that every full chunk is read out of.
+        //
+        // SAFETY: The `assume_init` is sound because `MaybeUninit`s do not require initialization.
+        let mut array: [MaybeUninit<I::Item>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        // Create panic guard. Its `Drop` impl takes care of any leftover, partially
+        // filled chunk if the inner iterator runs dry or a later call to `f` panics.
+        let mut guard = ChunkPanicGuard {
+            slice: &mut array,
+            initialized: 0,
+        };
+
+        let mut accum = Some(init);
+        // Drive the inner iterator through its own internal iteration (`for_each`)
+        // instead of pulling one item at a time through `next`, so inner iterators
+        // that specialize their internal iteration (slices, ranges, etc.) stay fast
+        // through this adapter.
+        self.inner.for_each(|item| {
+            // SAFETY: `initialized` is reset to 0 below every time it reaches N, so
+            // it never exceeds N here.
+            unsafe { guard.init_next_unchecked(item) };
+
+            if guard.initialized == N {
+                guard.initialized = 0;
+
+                // Cast to an array of definitely initialized items
+                //
+                // SAFETY: All N items were just initialized above.
+                //
+                // TODO: use `array_assume_init` when stabilized.
+                let chunk =
+                    unsafe { (guard.slice.as_ptr() as *const [I::Item; N]).read() };
+
+                accum = Some(f(accum.take().unwrap(), chunk));
+            }
+        });
+
+        accum.unwrap()
+    }
 }
 
 impl<const N: usize, I: ExactSizeIterator> ExactSizeIterator for ConstChunks<N, I> {
@@ -134,6 +212,141 @@ impl<const N: usize, I: ExactSizeIterator> ExactSizeIterator for ConstChunks<N,
     }
 }
 
+/// `next` returns `None` as soon as `inner` runs dry, and since `inner` is fused it
+/// keeps returning `None` from then on.
+impl<const N: usize, I: FusedIterator> FusedIterator for ConstChunks<N, I> {}
+
+impl<const N: usize, I: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator
+    for ConstChunks<N, I>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Chunks are front-aligned: the items that wouldn't have filled a whole chunk
+        // sit at the back of `inner`. Pull them out once, before assembling any back
+        // chunk, and stash them in `self.remainder` just like `next` does, so they stay
+        // recoverable through `remainder`/`into_remainder`.
+        if !self.trailing_discarded {
+            self.trailing_discarded = true;
+
+            let trailing = self.inner.len() % N;
+            if trailing > 0 {
+                // Create array of unitialized values
+                //
+                // SAFETY: The `assume_init` is sound because `MaybeUninit`s do not require initialization.
+                let mut array: [MaybeUninit<I::Item>; N] =
+                    unsafe { MaybeUninit::uninit().assume_init() };
+                // Create panic guard
+                let mut guard = ChunkPanicGuard {
+                    slice: &mut array,
+                    initialized: 0,
+                };
+
+                for _ in 0..trailing {
+                    let item = self
+                        .inner
+                        .next_back()
+                        .expect("ExactSizeIterator::len() did not match the items actually yielded");
+                    // SAFETY: Will be called at most `trailing` times, and `trailing <= N`.
+                    unsafe { guard.init_next_unchecked(item) };
+                }
+
+                // Disarm panic guard. At this point the trailing items are initialized
+                // and we're about to get rid of the `MaybeUninit`s.
+                forget(guard);
+
+                // Items were pulled back-to-front; reverse them to match the order they
+                // would have been yielded in by the remainder of a front `next` iteration.
+                array[..trailing].reverse();
+
+                self.remainder = Some(ConstChunksRemainder {
+                    remainder_chunk: array,
+                    init_range: 0..trailing,
+                });
+            }
+        }
+
+        // Early return if the underlying iterator is empty
+        let last_item = self.inner.next_back()?;
+
+        // Create array of unitialized values
+        //
+        // SAFETY: The `assume_init` is sound because `MaybeUninit`s do not require initialization.
+        let mut array: [MaybeUninit<I::Item>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        // Create panic guard
+        let mut guard = ChunkPanicGuard {
+            slice: &mut array,
+            initialized: 0,
+        };
+        // SAFETY: We enforce N > 0 at compile-time, so it's sound to assume at least one item.
+        unsafe { guard.init_next_unchecked(last_item) };
+
+        // Pull the remaining items, still from the back. The trailing remainder was
+        // already discarded above, so `inner.len()` guarantees these `N - 1` items exist.
+        for _ in 1..N {
+            let item = self
+                .inner
+                .next_back()
+                .expect("ExactSizeIterator::len() did not match the items actually yielded");
+            // SAFETY: Will be called at most N times (including the initial
+            // `init_next_unchecked` call before the loop)
+            unsafe { guard.init_next_unchecked(item) };
+        }
+
+        // Disarm panic guard. At this point all the items are initialized
+        // and we're about to get rid of the `MaybeUninit`s.
+        forget(guard);
+
+        // Cast to an array of definitely initialized items
+        //
+        // SAFETY: If we've reached this point, all the items in the chunk have been initialized.
+        //
+        // TODO: use `array_assume_init` when stabilized.
+        let mut init_arr = unsafe { (&array as *const _ as *const [I::Item; N]).read() };
+
+        // Items were pulled back-to-front; reverse them to match the front-chunk ordering.
+        init_arr.reverse();
+
+        Some(init_arr)
+    }
+}
+
+/// An iterator over constant-length chunks that surfaces a trailing partial chunk
+/// as a final `Err`, instead of silently dropping it.
+///
+/// This struct is created by the [`IteratorConstChunks::const_chunks_exact_or_err`]
+/// method. See its documentation for more.
+pub struct ConstChunksExactOrErr<const N: usize, I: Iterator> {
+    /// The chunk iterator that this wraps.
+    inner: ConstChunks<N, I>,
+    /// Whether the trailing `Err` has already been yielded (or there was none to yield).
+    done: bool,
+}
+
+impl<const N: usize, I: Iterator> Iterator for ConstChunksExactOrErr<N, I> {
+    type Item = Result<[I::Item; N], ConstChunksRemainder<N, I::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.inner.next() {
+            Some(chunk) => Some(Ok(chunk)),
+            None => {
+                self.done = true;
+                self.inner.remainder.take().map(Err)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.inner.size_hint();
+        (lower, upper.map(|upper| upper + 1))
+    }
+}
+
 /// An extension trait providing [`Iterator`]s with the capability to iterate
 /// over const-sized arrays of items.
 pub trait IteratorConstChunks {
@@ -191,6 +404,118 @@ pub trait IteratorConstChunks {
     ///     |                           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'chunk size must be non-zero'
     /// ```
     fn const_chunks<const N: usize>(self) -> ConstChunks<N, Self::Inner>;
+
+    /// Eagerly pulls exactly `N` items from the iterator by reference and returns them as an array.
+    ///
+    /// Unlike [`const_chunks`](IteratorConstChunks::const_chunks), this does not consume or wrap
+    /// the iterator: at most `N` calls to `next` are made, and the iterator remains usable
+    /// afterwards. If the iterator runs dry after only `k < N` items, those `k` items are not
+    /// lost; they are returned in the `Err` variant as a [`ConstChunksRemainder`] so the caller
+    /// can recover them.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// # #![allow(unstable_name_collisions)]
+    /// use const_chunks::IteratorConstChunks;
+    ///
+    /// let mut iter = vec![1, 2, 3, 4, 5].into_iter();
+    ///
+    /// assert_eq!(iter.next_chunk::<2>().ok().unwrap(), [1, 2]);
+    /// assert_eq!(iter.next_chunk::<2>().ok().unwrap(), [3, 4]);
+    ///
+    /// let remainder = iter.next_chunk::<2>().err().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(remainder, vec![5]);
+    /// ```
+    fn next_chunk<const N: usize>(
+        &mut self,
+    ) -> Result<[Self::Item; N], ConstChunksRemainder<N, Self::Item>>
+    where
+        Self: Iterator + Sized,
+    {
+        // Assert N > 0 (see `ConstChunks::N_GT_ZERO`)
+        #[allow(clippy::let_unit_value)]
+        let _ = ConstChunks::<N, Self>::N_GT_ZERO;
+
+        // Create array of unitialized values
+        //
+        // SAFETY: The `assume_init` is sound because `MaybeUninit`s do not require initialization.
+        let mut array: [MaybeUninit<Self::Item>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        // Create panic guard
+        let mut guard = ChunkPanicGuard {
+            slice: &mut array,
+            initialized: 0,
+        };
+
+        for _ in 0..N {
+            let Some(item) = self.next() else {
+                // Disarm panic guard. `ConstChunksRemainder` will
+                // handle the partially initialized array.
+                let init_range = 0..guard.initialized;
+                forget(guard);
+
+                return Err(ConstChunksRemainder {
+                    remainder_chunk: array,
+                    init_range,
+                });
+            };
+            // SAFETY: Will be called at most N times.
+            unsafe { guard.init_next_unchecked(item) };
+        }
+
+        // Disarm panic guard. At this point all the items are initialized
+        // and we're about to get rid of the `MaybeUninit`s.
+        forget(guard);
+
+        // Cast to an array of definitely initialized items
+        //
+        // SAFETY: If we've reached this point, all the items in the chunk have been initialized.
+        //
+        // TODO: use `array_assume_init` when stabilized.
+        let init_arr = unsafe { (&array as *const _ as *const [Self::Item; N]).read() };
+
+        Ok(init_arr)
+    }
+
+    /// Returns an iterator over constant-length chunks of items, where every full chunk
+    /// arrives as `Ok([T; N])` and, instead of silently dropping the leftover, the final
+    /// short group is emitted once as `Err(remainder)`.
+    ///
+    /// This lets callers who want "chunk evenly or tell me what was left over" just
+    /// `collect::<Result<Vec<_>, _>>()` and get either all the chunks or the partial
+    /// tail, without separately having to call [`ConstChunks::into_remainder`] after
+    /// draining the iterator.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use const_chunks::IteratorConstChunks;
+    ///
+    /// let v = vec![1, 2, 3, 4, 5, 6];
+    /// let chunks = v
+    ///     .into_iter()
+    ///     .const_chunks_exact_or_err::<2>()
+    ///     .collect::<Result<Vec<_>, _>>();
+    /// assert_eq!(chunks.ok(), Some(vec![[1, 2], [3, 4], [5, 6]]));
+    ///
+    /// // Five items cannot be divided evenly into chunks of length 2!
+    /// let v = vec![1, 2, 3, 4, 5];
+    /// let chunks = v
+    ///     .into_iter()
+    ///     .const_chunks_exact_or_err::<2>()
+    ///     .collect::<Result<Vec<_>, _>>();
+    /// let remainder = chunks.err().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(remainder, vec![5]);
+    /// ```
+    fn const_chunks_exact_or_err<const N: usize>(self) -> ConstChunksExactOrErr<N, Self::Inner>
+    where
+        Self: Sized,
+    {
+        ConstChunksExactOrErr {
+            inner: self.const_chunks(),
+            done: false,
+        }
+    }
 }
 
 /// Blanket implementation over all [`Iterator`]s.
@@ -205,6 +530,7 @@ impl<I: Iterator> IteratorConstChunks for I {
         ConstChunks {
             inner: self,
             remainder: None,
+            trailing_discarded: false,
         }
     }
 }
@@ -269,6 +595,23 @@ mod tests {
         assert_eq!(remainder, vec![5, 6]);
     }
 
+    #[test]
+    fn test_remainder_as_slice() {
+        let v = vec![1, 2, 3, 4, 5, 6];
+        let mut v_iter = v.into_iter().const_chunks::<4>();
+        let _ = (&mut v_iter).collect::<Vec<_>>();
+
+        // Borrowing the remainder doesn't consume it.
+        assert_eq!(v_iter.remainder(), Some(&[5, 6][..]));
+        assert_eq!(v_iter.remainder(), Some(&[5, 6][..]));
+
+        let mut remainder = v_iter.into_remainder().unwrap();
+        assert_eq!(remainder.as_slice(), &[5, 6]);
+
+        remainder.as_mut_slice()[0] = 50;
+        assert_eq!(remainder.collect::<Vec<_>>(), vec![50, 6]);
+    }
+
     #[test]
     fn test_remainder_leak() {
         let mut v_iter = (1..=6).map(|n| n.to_string()).const_chunks::<4>();
@@ -283,4 +626,259 @@ mod tests {
         assert_eq!(remainder.next(), Some(5.to_string()));
         drop(remainder);
     }
+
+    #[test]
+    #[allow(unstable_name_collisions)]
+    fn test_next_chunk() {
+        let mut v_iter = (1..=5).map(|n| n.to_string());
+
+        assert_eq!(
+            v_iter.next_chunk::<2>().ok(),
+            Some([1, 2].map(|n| n.to_string()))
+        );
+        assert_eq!(
+            v_iter.next_chunk::<2>().ok(),
+            Some([3, 4].map(|n| n.to_string()))
+        );
+
+        // Only one item left: the iterator stays usable and hands back
+        // what it managed to pull.
+        let remainder = v_iter.next_chunk::<2>().unwrap_err().collect::<Vec<_>>();
+        assert_eq!(remainder, vec![5.to_string()]);
+    }
+
+    #[test]
+    #[allow(unstable_name_collisions)]
+    fn test_next_chunk_panic_leak() {
+        // Setup an iterator that can panic on `next`.
+        struct PanicIter<I: Iterator> {
+            inner: I,
+        }
+        impl<I: Iterator> Iterator for PanicIter<I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                // Causes a panic when the inner iterator is exhausted
+                Some(self.inner.next().unwrap())
+            }
+        }
+        let mut panic_iter = PanicIter {
+            inner: [String::from("1")].into_iter(),
+        };
+
+        // Catch the panic to try to cause a leak
+        let _ = catch_unwind(move || panic_iter.next_chunk::<4>());
+    }
+
+    #[test]
+    fn test_next_back() {
+        let v = vec![1, 2, 3, 4, 5, 6];
+        let mut v_iter = v.into_iter().const_chunks::<2>();
+        assert_eq!(v_iter.next_back(), Some([5, 6]));
+        assert_eq!(v_iter.next_back(), Some([3, 4]));
+        assert_eq!(v_iter.next_back(), Some([1, 2]));
+        assert_eq!(v_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_discards_trailing_remainder() {
+        // Seven items cannot fit into chunks of length 2: the trailing `7` must be
+        // discarded before any chunk can be pulled from the back.
+        let v = vec![1, 2, 3, 4, 5, 6, 7];
+        let mut v_iter = v.into_iter().const_chunks::<2>();
+        assert_eq!(v_iter.next_back(), Some([5, 6]));
+        assert_eq!(v_iter.next_back(), Some([3, 4]));
+        assert_eq!(v_iter.next_back(), Some([1, 2]));
+        assert_eq!(v_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_keeps_trailing_remainder_recoverable() {
+        // The trailing `5` is discarded from the back on the very first `next_back`
+        // call, but it must remain recoverable through `remainder`/`into_remainder`
+        // instead of being dropped on the floor.
+        let v = vec![1, 2, 3, 4, 5];
+        let mut v_iter = v.into_iter().const_chunks::<2>();
+        assert_eq!(v_iter.next_back(), Some([3, 4]));
+        assert_eq!(v_iter.remainder().unwrap(), &[5]);
+        assert_eq!(v_iter.next_back(), Some([1, 2]));
+        assert_eq!(
+            v_iter.into_remainder().unwrap().collect::<Vec<_>>(),
+            vec![5]
+        );
+    }
+
+    #[test]
+    fn test_next_and_next_back_meet_in_the_middle() {
+        let v = vec![1, 2, 3, 4, 5, 6];
+        let mut v_iter = v.into_iter().const_chunks::<2>();
+        assert_eq!(v_iter.next(), Some([1, 2]));
+        assert_eq!(v_iter.next_back(), Some([5, 6]));
+        assert_eq!(v_iter.next(), Some([3, 4]));
+        assert_eq!(v_iter.next(), None);
+        assert_eq!(v_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_panic_leak() {
+        // An iterator whose reported length lies about what `next_back` can actually
+        // yield, forcing `ConstChunks::next_back` to panic partway through filling a
+        // chunk so we can check that the already-initialized item doesn't leak.
+        struct LyingLen<I> {
+            inner: I,
+            len: usize,
+        }
+        impl<I: Iterator> Iterator for LyingLen<I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+        impl<I: DoubleEndedIterator> DoubleEndedIterator for LyingLen<I> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+        impl<I: Iterator> ExactSizeIterator for LyingLen<I> {
+            fn len(&self) -> usize {
+                self.len
+            }
+        }
+
+        // Catch the panic to try to cause a leak
+        let _ = catch_unwind(|| {
+            let mut chunks = LyingLen {
+                inner: [String::from("1")].into_iter(),
+                len: 2,
+            }
+            .const_chunks::<2>();
+            chunks.next_back()
+        });
+    }
+
+    #[test]
+    fn test_next_back_discard_loop_panic_leak() {
+        // An iterator whose reported length lies about what `next_back` can actually
+        // yield, forcing the trailing-discard loop (which runs once before the first
+        // back chunk is assembled) to panic partway through, so we can check that the
+        // already-initialized item doesn't leak. `len: 2` over chunks of size 3 makes
+        // `trailing = len % N == 2`, so the loop needs two items but the inner
+        // iterator only has one to give.
+        struct LyingLen<I> {
+            inner: I,
+            len: usize,
+        }
+        impl<I: Iterator> Iterator for LyingLen<I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+        impl<I: DoubleEndedIterator> DoubleEndedIterator for LyingLen<I> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+        impl<I: Iterator> ExactSizeIterator for LyingLen<I> {
+            fn len(&self) -> usize {
+                self.len
+            }
+        }
+
+        // Catch the panic to try to cause a leak
+        let _ = catch_unwind(|| {
+            let mut chunks = LyingLen {
+                inner: [String::from("1")].into_iter(),
+                len: 2,
+            }
+            .const_chunks::<3>();
+            chunks.next_back()
+        });
+    }
+
+    #[test]
+    fn test_fold() {
+        let v = vec![1, 2, 3, 4, 5, 6];
+        let sum = v
+            .into_iter()
+            .const_chunks::<2>()
+            .fold(0, |acc, [a, b]| acc + a + b);
+        assert_eq!(sum, 21);
+    }
+
+    #[test]
+    fn test_fold_exhausted() {
+        // Five items cannot fit into chunks of length 2: the trailing `5` must not be
+        // folded over.
+        let v = vec![1, 2, 3, 4, 5];
+        let chunks = v.into_iter().const_chunks::<2>().fold(Vec::new(), |mut acc, chunk| {
+            acc.push(chunk);
+            acc
+        });
+        assert_eq!(chunks, vec![[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn test_fold_panic_leak() {
+        // Setup an iterator that can panic on `next`.
+        struct PanicIter<I: Iterator> {
+            inner: I,
+        }
+        impl<I: Iterator> Iterator for PanicIter<I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                // Causes a panic when the inner iterator is exhausted
+                Some(self.inner.next().unwrap())
+            }
+        }
+        let panic_iter = PanicIter {
+            inner: [String::from("1")].into_iter(),
+        };
+
+        // Catch the panic to try to cause a leak
+        let _ = catch_unwind(|| panic_iter.const_chunks::<4>().fold((), |(), _| ()));
+    }
+
+    #[test]
+    fn test_fused() {
+        fn assert_fused(_: impl std::iter::FusedIterator) {}
+
+        assert_fused([1, 2, 3].into_iter().const_chunks::<2>());
+    }
+
+    #[test]
+    fn test_size_hint_exact_with_exact_size_iterator() {
+        let v_iter = vec![1, 2, 3, 4, 5].into_iter().const_chunks::<2>();
+        assert_eq!(v_iter.size_hint(), (2, Some(2)));
+        assert_eq!(v_iter.len(), 2);
+    }
+
+    #[test]
+    fn test_const_chunks_exact_or_err_trailing_remainder() {
+        // Five items cannot be divided evenly into chunks of length 2.
+        let v = vec![1, 2, 3, 4, 5];
+        let mut chunks = v.into_iter().const_chunks_exact_or_err::<2>();
+        assert_eq!(chunks.next().unwrap().ok(), Some([1, 2]));
+        assert_eq!(chunks.next().unwrap().ok(), Some([3, 4]));
+
+        let remainder = chunks.next().unwrap().err().unwrap().collect::<Vec<_>>();
+        assert_eq!(remainder, vec![5]);
+
+        // The trailing remainder is only yielded once.
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_const_chunks_exact_or_err_no_remainder() {
+        // Six items divide evenly into chunks of length 2: there's nothing to report.
+        let v = vec![1, 2, 3, 4, 5, 6];
+        let mut chunks = v.into_iter().const_chunks_exact_or_err::<2>();
+        assert_eq!(chunks.next().unwrap().ok(), Some([1, 2]));
+        assert_eq!(chunks.next().unwrap().ok(), Some([3, 4]));
+        assert_eq!(chunks.next().unwrap().ok(), Some([5, 6]));
+        assert!(chunks.next().is_none());
+    }
 }
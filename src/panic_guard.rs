@@ -5,14 +5,18 @@ use crate::drop_slice;
 /// This type acts as a guard that drops the currently initialized
 /// items when itself is dropped. This prevents leaking memory when
 /// a panic occurs during chunk initialization.
-pub struct PanicGuard<'a, T> {
+///
+/// Named `ChunkPanicGuard` (rather than just `PanicGuard`) because it's
+/// specific to guarding a single in-progress chunk, as opposed to a more
+/// general-purpose panic guard.
+pub struct ChunkPanicGuard<'a, T> {
     /// The array being initialized.
     pub slice: &'a mut [MaybeUninit<T>],
     /// The number of items that have been initialized so far.
     pub initialized: usize,
 }
 
-impl<'a, T> PanicGuard<'a, T> {
+impl<'a, T> ChunkPanicGuard<'a, T> {
     /// Initializes the next uninitialized item and updates the initialized item counter.
     ///
     /// # Safety
@@ -25,7 +29,7 @@ impl<'a, T> PanicGuard<'a, T> {
     }
 }
 
-impl<'a, T> Drop for PanicGuard<'a, T> {
+impl<'a, T> Drop for ChunkPanicGuard<'a, T> {
     /// Drops all the initialized items in the slice.
     fn drop(&mut self) {
         // SAFETY: The slice contains only initialized objects.
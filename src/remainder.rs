@@ -14,6 +14,49 @@ pub struct ConstChunksRemainder<const N: usize, T> {
     pub(crate) init_range: Range<usize>,
 }
 
+impl<const N: usize, T> ConstChunksRemainder<N, T> {
+    /// Returns the remaining items as a slice, without consuming them.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use const_chunks::IteratorConstChunks;
+    ///
+    /// let mut v_iter = vec![1, 2, 3, 4, 5, 6].into_iter().const_chunks::<4>();
+    /// let _ = (&mut v_iter).collect::<Vec<_>>();
+    ///
+    /// let remainder = v_iter.into_remainder().unwrap();
+    /// assert_eq!(remainder.as_slice(), &[5, 6]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        let init = &self.remainder_chunk[self.init_range.clone()];
+        // SAFETY: `init_range`, by invariant, only ever covers the initialized
+        // portion of `remainder_chunk`.
+        unsafe { &*(init as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// Returns the remaining items as a mutable slice, without consuming them.
+    ///
+    /// # Usage
+    ///
+    /// ```rust
+    /// use const_chunks::IteratorConstChunks;
+    ///
+    /// let mut v_iter = vec![1, 2, 3, 4, 5, 6].into_iter().const_chunks::<4>();
+    /// let _ = (&mut v_iter).collect::<Vec<_>>();
+    ///
+    /// let mut remainder = v_iter.into_remainder().unwrap();
+    /// remainder.as_mut_slice()[0] = 50;
+    /// assert_eq!(remainder.collect::<Vec<_>>(), vec![50, 6]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let init = &mut self.remainder_chunk[self.init_range.clone()];
+        // SAFETY: `init_range`, by invariant, only ever covers the initialized
+        // portion of `remainder_chunk`.
+        unsafe { &mut *(init as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
 impl<const N: usize, T> Iterator for ConstChunksRemainder<N, T> {
     type Item = T;
 